@@ -0,0 +1,106 @@
+/*******************************************************************************
+* Wordle SolvRS - A wordle solver written in Rust
+*
+* The MIT License (MIT)
+* Copyright (c) 2025 Teeto44
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to
+* deal in the Software without restriction, including without limitation the
+* rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+* sell copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in all
+* copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*******************************************************************************/
+
+use crate::fst_index::FstIndex;
+use crate::solver::solve_silent;
+use crate::strategy::Solver;
+use rayon::prelude::*;
+
+/// Summary statistics produced by running the solver against every word in the list.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub total: usize,
+    pub wins: usize,
+    pub failures: usize,
+    pub average_guesses: f64,
+    pub worst: usize,
+    /// `histogram[i]` is the number of answers solved in exactly `i + 1` guesses.
+    pub histogram: Vec<usize>,
+}
+
+/// Runs the solver in test mode against every word in `word_list` as the hidden answer,
+/// parallelized with rayon since each run only touches its own local state, and collects
+/// win rate, average guess count, worst case, and a guess-count histogram.
+pub fn run_bench(
+    word_list: &[String],
+    first_word: &str,
+    max_guesses: usize,
+    solver: &dyn Solver,
+    fst_index: Option<&FstIndex>,
+) -> BenchReport {
+    let outcomes: Vec<_> = word_list
+        .par_iter()
+        .map(|answer| solve_silent(word_list, answer, first_word, max_guesses, solver, fst_index))
+        .collect();
+
+    let mut histogram = vec![0usize; max_guesses];
+    let mut wins = 0;
+    let mut total_guesses = 0usize;
+    let mut worst = 0usize;
+
+    for outcome in &outcomes {
+        if outcome.solved {
+            wins += 1;
+            total_guesses += outcome.guesses;
+            histogram[outcome.guesses - 1] += 1;
+            worst = worst.max(outcome.guesses);
+        }
+    }
+
+    BenchReport {
+        total: outcomes.len(),
+        wins,
+        failures: outcomes.len() - wins,
+        average_guesses: if wins > 0 {
+            total_guesses as f64 / wins as f64
+        } else {
+            0.0
+        },
+        worst,
+        histogram,
+    }
+}
+
+/// Prints a human-readable summary of a benchmark report.
+pub fn print_report(report: &BenchReport, solver: &dyn Solver) {
+    println!("Wordle SolvRS - Benchmark");
+    println!("  Solver: {}", solver.name());
+    println!("  Answers tested: {}", report.total);
+    println!(
+        "  Win rate: {:.2}% ({}/{})",
+        report.wins as f64 / report.total as f64 * 100.0,
+        report.wins,
+        report.total
+    );
+    println!("  Average guesses (wins only): {:.3}", report.average_guesses);
+    println!("  Worst case: {}", report.worst);
+    println!("  Guess distribution:");
+    for (index, count) in report.histogram.iter().enumerate() {
+        println!("    {}: {}", index + 1, count);
+    }
+    if report.failures > 0 {
+        println!("  Failed to solve: {}", report.failures);
+    }
+}
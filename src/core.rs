@@ -23,6 +23,7 @@
 * THE SOFTWARE.
 *******************************************************************************/
 
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 /// Maximum allowed guesses.
@@ -32,7 +33,7 @@ pub const DEFAULT_MAX_GUESSES: usize = 6;
 pub const DEFAULT_FIRST_WORD: &str = "reads";
 
 /// Wordle feedback types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Feedback {
     Green,
     Yellow,
@@ -48,6 +49,49 @@ impl Feedback {
             _ => None,
         }
     }
+
+    /// Parses a 5‑character feedback string like `gbybb` into an array, or `None` if it's the
+    /// wrong length or contains an invalid character.
+    pub fn parse_str(input: &str) -> Option<[Feedback; 5]> {
+        if input.len() != 5 {
+            return None;
+        }
+        let mut feedback = [Feedback::Gray; 5];
+        for (index, char) in input.chars().enumerate() {
+            feedback[index] = Feedback::from_char(char)?;
+        }
+        Some(feedback)
+    }
+}
+
+/// Accumulated constraints derived from every guess's feedback so far: which letters are known
+/// to sit in which position (green), which are present but misplaced (yellow), which are absent
+/// (gray), and the minimum confirmed count for any repeated letter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SolverState {
+    pub green: [Option<char>; 5],
+    pub yellow: Vec<(char, usize)>,
+    pub gray: HashSet<char>,
+    pub min_counts: HashMap<char, usize>,
+}
+
+impl SolverState {
+    /// Filters `words` down to those consistent with the state accumulated so far.
+    pub fn filter_candidates(&self, words: &[String]) -> Vec<String> {
+        filter_candidates(words, &self.green, &self.yellow, &self.gray, &self.min_counts)
+    }
+
+    /// Folds a guess's feedback into the state in‑place.
+    pub fn apply_feedback(&mut self, guess: &str, feedback: &[Feedback; 5]) {
+        apply_feedback(
+            guess,
+            feedback,
+            &mut self.green,
+            &mut self.yellow,
+            &mut self.gray,
+            &mut self.min_counts,
+        );
+    }
 }
 
 /// Choose the highest‑scoring possible candidate or return None if there are no possible words.
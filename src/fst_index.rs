@@ -0,0 +1,262 @@
+/*******************************************************************************
+* Wordle SolvRS - A wordle solver written in Rust
+*
+* The MIT License (MIT)
+* Copyright (c) 2025 Teeto44
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to
+* deal in the Software without restriction, including without limitation the
+* rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+* sell copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in all
+* copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*******************************************************************************/
+
+use crate::core::SolverState;
+use fst::{Automaton, IntoStreamer, Set, Streamer};
+use std::collections::{HashMap, HashSet};
+
+/// Per-byte automaton state: how many letters of the 5 have been consumed, a running count of
+/// each letter seen so far (to check minimum-count constraints at the end), and whether any
+/// constraint has already been violated.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstraintState {
+    position: usize,
+    counts: [u8; 26],
+    dead: bool,
+}
+
+/// An `fst::Automaton` that encodes the accumulated green/yellow/gray/min-count constraints from
+/// a `SolverState`, so `fst::Set::search` can stream only the words that satisfy them without
+/// scanning the rest of the word list.
+pub struct ConstraintAutomaton<'a> {
+    green: &'a [Option<char>; 5],
+    yellow: &'a [(char, usize)],
+    gray: &'a HashSet<char>,
+    min_counts: &'a HashMap<char, usize>,
+}
+
+impl<'a> ConstraintAutomaton<'a> {
+    pub fn new(state: &'a SolverState) -> Self {
+        ConstraintAutomaton {
+            green: &state.green,
+            yellow: &state.yellow,
+            gray: &state.gray,
+            min_counts: &state.min_counts,
+        }
+    }
+}
+
+/// Maps `'a'..='z'` to `0..26` for indexing `ConstraintState::counts`, or `None` for any other
+/// character (the word list is always lowercase, so such a character can never occur in it).
+fn lowercase_index(char: char) -> Option<usize> {
+    if char.is_ascii_lowercase() {
+        Some(char as usize - 'a' as usize)
+    } else {
+        None
+    }
+}
+
+impl Automaton for ConstraintAutomaton<'_> {
+    type State = ConstraintState;
+
+    fn start(&self) -> Self::State {
+        ConstraintState {
+            position: 0,
+            counts: [0; 26],
+            dead: false,
+        }
+    }
+
+    fn is_match(&self, state: &Self::State) -> bool {
+        if state.dead || state.position != 5 {
+            return false;
+        }
+        // A non-lowercase-letter key (e.g. from an unvalidated guess) can never be satisfied by
+        // any word in the set, so it fails the match rather than indexing `counts` out of bounds.
+        self.min_counts.iter().all(|(&char, &minimum)| {
+            match lowercase_index(char) {
+                Some(index) => state.counts[index] as usize >= minimum,
+                None => minimum == 0,
+            }
+        })
+    }
+
+    fn can_match(&self, state: &Self::State) -> bool {
+        !state.dead
+    }
+
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        if state.dead || state.position >= 5 || !byte.is_ascii_lowercase() {
+            return ConstraintState {
+                dead: true,
+                ..*state
+            };
+        }
+
+        let char = byte as char;
+        let mut dead = false;
+
+        // Greens: the byte at this position must match a fixed letter, if one is known.
+        if let Some(required) = self.green[state.position] {
+            if required != char {
+                dead = true;
+            }
+        }
+
+        // Yellows: the letter is known to be absent from this exact position.
+        if self
+            .yellow
+            .iter()
+            .any(|&(yellow_char, position)| position == state.position && yellow_char == char)
+        {
+            dead = true;
+        }
+
+        // Grays: a letter with no confirmed occurrences anywhere is fully forbidden.
+        if self.gray.contains(&char) && !self.min_counts.contains_key(&char) {
+            dead = true;
+        }
+
+        let mut counts = state.counts;
+        counts[byte as usize - 'a' as usize] += 1;
+
+        ConstraintState {
+            position: state.position + 1,
+            counts,
+            dead,
+        }
+    }
+}
+
+/// An `fst::Set` built once from the word list, letting candidate filtering stream only the
+/// accepted words instead of linearly scanning and reallocating a `Vec<char>` per word per round.
+pub struct FstIndex {
+    set: Set<Vec<u8>>,
+    /// Each word's index in the original word list, so results can be restored to that order.
+    /// The fst stream yields matches in lexicographic order, which would otherwise shuffle which
+    /// candidate a tie-breaking strategy like `Naive` picks first compared to the linear scan.
+    positions: HashMap<String, usize>,
+}
+
+impl FstIndex {
+    /// Builds the set from a word list. Requires the fst crate's sorted-key invariant, so the
+    /// words are sorted and deduplicated first.
+    pub fn build(words: &[String]) -> Result<Self, fst::Error> {
+        let mut sorted: Vec<&String> = words.iter().collect();
+        sorted.sort();
+        sorted.dedup();
+        let set = Set::from_iter(sorted)?;
+        let positions = words
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (word.clone(), index))
+            .collect();
+        Ok(FstIndex { set, positions })
+    }
+
+    /// Streams the words matching the current constraints out of the set, then restores
+    /// word-list order so it matches the linear scan's candidate ordering exactly.
+    pub fn filter_candidates(&self, state: &SolverState) -> Vec<String> {
+        let automaton = ConstraintAutomaton::new(state);
+        let mut stream = self.set.search(automaton).into_stream();
+        let mut matches = Vec::new();
+        while let Some(word) = stream.next() {
+            if let Ok(word) = std::str::from_utf8(word) {
+                matches.push(word.to_string());
+            }
+        }
+        matches.sort_by_key(|word| self.positions.get(word).copied().unwrap_or(usize::MAX));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::generate_feedback;
+
+    fn word_list() -> Vec<String> {
+        [
+            "reads", "adieu", "stare", "crane", "slate", "trace", "least", "arise", "raise",
+            "tears", "rates", "store", "stone", "shore", "score",
+        ]
+        .iter()
+        .map(|w| w.to_string())
+        .collect()
+    }
+
+    /// Replays `guesses` against `answer`, applying real feedback each round, so the resulting
+    /// state exercises green, yellow, gray, and min-count constraints together the way actual
+    /// play would.
+    fn state_after(guesses: &[&str], answer: &str) -> SolverState {
+        let mut state = SolverState::default();
+        for guess in guesses {
+            let feedback = generate_feedback(guess, answer);
+            state.apply_feedback(guess, &feedback);
+        }
+        state
+    }
+
+    #[test]
+    fn fst_matches_linear_scan_across_states() {
+        let words = word_list();
+        let index = FstIndex::build(&words).expect("fst builds from a sorted word list");
+
+        let cases: &[(&[&str], &str)] = &[
+            (&[], "store"),
+            (&["reads"], "store"),
+            (&["adieu"], "crane"),
+            (&["reads", "stare"], "store"),
+            (&["crane", "slate"], "stone"),
+            (&["store", "shore"], "score"),
+            (&["tears", "rates"], "arise"),
+        ];
+
+        for (guesses, answer) in cases {
+            let state = state_after(guesses, answer);
+
+            let mut linear = state.filter_candidates(&words);
+            let mut fst = index.filter_candidates(&state);
+
+            // Order should match exactly (see `FstIndex::positions`); sort only as a defensive
+            // check against future regressions in either path before the final equality check.
+            assert_eq!(
+                fst, linear,
+                "fst and linear results diverged in order for guesses {:?} / answer {}",
+                guesses, answer
+            );
+
+            linear.sort();
+            fst.sort();
+            assert_eq!(
+                fst, linear,
+                "fst and linear results diverged in content for guesses {:?} / answer {}",
+                guesses, answer
+            );
+        }
+    }
+
+    #[test]
+    fn fst_matches_linear_scan_with_unvalidated_uppercase_guess() {
+        let words = word_list();
+        let index = FstIndex::build(&words).expect("fst builds from a sorted word list");
+
+        let mut state = SolverState::default();
+        state.apply_feedback("HELLO", &generate_feedback("hello", "store"));
+
+        assert_eq!(index.filter_candidates(&state), Vec::<String>::new());
+        assert_eq!(state.filter_candidates(&words), Vec::<String>::new());
+    }
+}
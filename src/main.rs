@@ -23,12 +23,21 @@
 * THE SOFTWARE.
 *******************************************************************************/
 
+mod bench;
 mod core;
+mod fst_index;
+mod repl;
+mod save;
 mod solver;
+mod strategy;
 
 use crate::core::DEFAULT_MAX_GUESSES;
+use bench::{print_report, run_bench};
 use core::DEFAULT_FIRST_WORD;
+use fst_index::FstIndex;
 use prompted::input;
+use repl::run_repl;
+use save::SavedRound;
 use solver::solve;
 use std::{env, fs};
 
@@ -43,7 +52,8 @@ fn main() {
 
     let word_list = load_words(get_option(&args, &["-w", "--words"]));
     let first_word = get_first_word(&args, &word_list);
-    let state = get_option(&args, &["-s", "--state"]);
+    let initial_rounds = get_initial_rounds(&args);
+    let save_path = get_option(&args, &["--save"]);
 
     // Check for requested test word
     let test_word = match get_option(&args, &["-t", "--test"]) {
@@ -70,13 +80,87 @@ fn main() {
         None => DEFAULT_MAX_GUESSES,
     };
 
+    let solver = strategy::resolve(get_option(&args, &["--solver"]).as_deref(), &word_list);
+
+    // Build the FST-backed candidate index once, if requested
+    let fst_index = if args.iter().any(|a| a == "--fst") {
+        match FstIndex::build(&word_list) {
+            Ok(index) => Some(index),
+            Err(err) => {
+                eprintln!("Error: couldn't build FST index: {}. Falling back to linear scan.", err);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    // Check for benchmark mode
+    if args.iter().any(|a| a == "--bench") {
+        let report = run_bench(
+            &word_list,
+            &first_word,
+            max_guesses,
+            solver.as_ref(),
+            fst_index.as_ref(),
+        );
+        print_report(&report, solver.as_ref());
+        return;
+    }
+
+    // Check for REPL mode
+    if args.iter().any(|a| a == "--repl" || a == "-r") {
+        run_repl(
+            word_list,
+            first_word,
+            max_guesses,
+            solver.as_ref(),
+            fst_index.as_ref(),
+        );
+        return;
+    }
+
     if let Some(answer) = test_word {
-        solve(word_list, Some(answer), None, Some(first_word), max_guesses);
+        solve(
+            word_list,
+            Some(answer),
+            None,
+            Some(first_word),
+            max_guesses,
+            solver.as_ref(),
+            fst_index.as_ref(),
+            save_path.as_deref(),
+        );
     } else {
-        solve(word_list, None, state, Some(first_word), max_guesses);
+        solve(
+            word_list,
+            None,
+            initial_rounds,
+            Some(first_word),
+            max_guesses,
+            solver.as_ref(),
+            fst_index.as_ref(),
+            save_path.as_deref(),
+        );
     }
 }
 
+/// Resolves the round history to resume from: a JSON save file takes priority over the legacy
+/// comma-separated `-s/--state` string, so users mid-migration can pass either.
+fn get_initial_rounds(args: &[String]) -> Option<Vec<SavedRound>> {
+    if let Some(path) = get_option(args, &["--load"]) {
+        return match save::load_from_file(&path) {
+            Ok(game) => Some(game.rounds),
+            Err(err) => {
+                eprintln!("Error: couldn't load game from `{}`: {}", path, err);
+                None
+            }
+        };
+    }
+
+    get_option(args, &["-s", "--state"]).map(|state| save::parse_legacy_state(&state))
+}
+
 /// Helper function to get an option from the command line arguments.
 fn get_option(args: &[String], flags: &[&str]) -> Option<String> {
     args.windows(2)
@@ -127,17 +211,38 @@ fn print_usage() {
     println!("  -h or --help: Show this help message");
     println!("  -t or --test <word>: Loads the solver in test mode");
     println!("  -f or --first <word>: Will make the solver use a specified first word");
-    println!("  -s or --state <state>: Will load a given game state");
+    println!("  -s or --state <state>: Will load a given game state (legacy string format)");
+    println!("  --load <path>: Resumes a session saved with `--save` (takes priority over `-s`; ignored in test mode)");
+    println!("  --save <path>: Saves the full round history to a JSON file once the run finishes");
     println!("  -w or --words <path>: Will make the solver use a custom word list");
     println!("  -g or --guesses <number>: Set custom maximum guesses");
+    println!("  --bench: Scores the solver against every word in the word list");
+    println!("  --solver <name>: Picks the guess strategy (`naive`, `frequency`, or `entropy`)");
+    println!("  --fst: Builds an FST-backed index for faster candidate filtering");
+    println!("  -r or --repl: Starts an interactive REPL mixing manual and solver-suggested guesses");
+    println!();
+    println!("Wordle SolvRS - REPL mode help");
+    println!("  guess <word> <feedback>: Records a guess you played yourself");
+    println!("  solve: Has the solver propose and apply its own suggestion");
+    println!("  candidates: Lists how many words remain (and which, when 20 or fewer)");
+    println!("  undo: Reverts the last applied guess");
+    println!("  save <path>: Saves the round history played so far to a JSON file");
+    println!("  load <path>: Replaces the round history with one loaded from a JSON file");
+    println!("  quit: Exits the REPL");
     println!();
     println!("Wordle SolvRS - test mode help");
     println!("  The test mode will make the solver use a given word as the answer");
     println!();
-    println!("Wordle SolvRS - state loading help");
+    println!("Wordle SolvRS - session saving help");
+    println!("  --save <path> writes the full round history to a JSON file once the run completes");
+    println!("  --save works in test mode too, capturing that single run's rounds");
+    println!("  --load <path> resumes from a file written by --save");
+    println!("  --load (like legacy -s/--state below) is ignored in test mode");
+    println!();
+    println!("Wordle SolvRS - legacy state loading help");
     println!("  after -s you can provide a string with the current state of the game");
     println!("  the guesses should be separated by commas, and be a tuple of guess and feedback");
-    println!("  this won't work in test mode");
+    println!("  this won't work in test mode, and is ignored if --load is also given");
     println!("  Example: slateybbbb,pastsgbbbg");
     println!();
     println!("Wordle SolvRS - feedback help");
@@ -0,0 +1,201 @@
+/*******************************************************************************
+* Wordle SolvRS - A wordle solver written in Rust
+*
+* The MIT License (MIT)
+* Copyright (c) 2025 Teeto44
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to
+* deal in the Software without restriction, including without limitation the
+* rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+* sell copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in all
+* copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*******************************************************************************/
+
+use crate::core::{Feedback, SolverState};
+use crate::fst_index::FstIndex;
+use crate::save::{self, SavedGame, SavedRound};
+use crate::solver::manual_feedback;
+use crate::strategy::Solver;
+use prompted::input;
+
+/// Runs the interactive REPL, letting the user mix guesses they actually played with solver
+/// suggestions instead of committing to test or fully‑manual mode up front.
+pub fn run_repl(
+    word_list: Vec<String>,
+    first_word: String,
+    max_guesses: usize,
+    solver: &dyn Solver,
+    fst_index: Option<&FstIndex>,
+) {
+    println!("Wordle SolvRS - REPL Mode");
+    println!("Commands: guess <word> <feedback> | solve | candidates | undo | save <path> | load <path> | quit");
+
+    let mut state = SolverState::default();
+    let mut history: Vec<SavedRound> = Vec::new();
+    let mut snapshots: Vec<SolverState> = Vec::new();
+
+    loop {
+        if history.len() >= max_guesses {
+            println!("Reached the maximum of {} guesses.", max_guesses);
+            break;
+        }
+
+        let candidates = filter_candidates(&word_list, &state, fst_index);
+        let line = input!("> ").trim().to_string();
+        let mut parts = line.split_whitespace();
+
+        match parts.next() {
+            Some("guess") => {
+                let word = match parts.next() {
+                    Some(word) => word,
+                    None => {
+                        eprintln!("Usage: guess <word> <feedback>");
+                        continue;
+                    }
+                };
+                let feedback_arg = match parts.next() {
+                    Some(feedback_arg) => feedback_arg,
+                    None => {
+                        eprintln!("Usage: guess <word> <feedback>");
+                        continue;
+                    }
+                };
+                if word.len() != 5 {
+                    eprintln!("Error: guess must be 5 characters.");
+                    continue;
+                }
+                let feedback = match Feedback::parse_str(feedback_arg) {
+                    Some(feedback) => feedback,
+                    None => {
+                        eprintln!("Error: feedback must be 5 characters of g/y/b.");
+                        continue;
+                    }
+                };
+
+                snapshots.push(state.clone());
+                state.apply_feedback(word, &feedback);
+                history.push(SavedRound {
+                    guess: word.to_string(),
+                    feedback,
+                    solver_chosen: false,
+                });
+                report_round(&history, max_guesses);
+            }
+            Some("solve") => {
+                let guess = if history.is_empty() {
+                    first_word.clone()
+                } else {
+                    match solver.next_guess(&candidates, &word_list, &state) {
+                        Some((word, _)) => word,
+                        None => {
+                            eprintln!("Error: no possible candidates.");
+                            continue;
+                        }
+                    }
+                };
+
+                println!("Solver suggests: {}", guess);
+                let feedback = manual_feedback(&guess);
+
+                snapshots.push(state.clone());
+                state.apply_feedback(&guess, &feedback);
+                history.push(SavedRound {
+                    guess,
+                    feedback,
+                    solver_chosen: true,
+                });
+                report_round(&history, max_guesses);
+            }
+            Some("candidates") => {
+                println!("{} candidates remain.", candidates.len());
+                if candidates.len() <= 20 {
+                    println!("{}", candidates.join(", "));
+                }
+            }
+            Some("undo") => match (history.pop(), snapshots.pop()) {
+                (Some(round), Some(previous)) => {
+                    state = previous;
+                    println!("Undid guess `{}`.", round.guess);
+                }
+                _ => eprintln!("Nothing to undo."),
+            },
+            Some("save") => match parts.next() {
+                Some(path) => {
+                    let game = SavedGame::from_rounds(history.clone());
+                    match save::save_to_file(path, &game) {
+                        Ok(()) => println!("Saved {} rounds to `{}`.", history.len(), path),
+                        Err(err) => eprintln!("Error: couldn't save game to `{}`: {}", path, err),
+                    }
+                }
+                None => eprintln!("Usage: save <path>"),
+            },
+            Some("load") => match parts.next() {
+                Some(path) => match save::load_from_file(path) {
+                    Ok(game) => {
+                        state = SolverState::default();
+                        snapshots.clear();
+                        for round in &game.rounds {
+                            snapshots.push(state.clone());
+                            state.apply_feedback(&round.guess, &round.feedback);
+                        }
+                        history = game.rounds;
+                        println!("Loaded {} rounds from `{}`.", history.len(), path);
+                    }
+                    Err(err) => eprintln!("Error: couldn't load game from `{}`: {}", path, err),
+                },
+                None => eprintln!("Usage: load <path>"),
+            },
+            Some("quit") | Some("exit") => break,
+            Some(other) => eprintln!("Unknown command `{}`.", other),
+            None => continue,
+        }
+
+        if let Some(round) = history.last() {
+            if round.feedback.iter().all(|&f| f == Feedback::Green) {
+                println!("Solved in {} rounds.", history.len());
+                break;
+            }
+        }
+    }
+}
+
+/// Filters candidates through the FST-backed index when one was built, falling back to the
+/// linear scan otherwise.
+fn filter_candidates(
+    word_list: &[String],
+    state: &SolverState,
+    fst_index: Option<&FstIndex>,
+) -> Vec<String> {
+    match fst_index {
+        Some(index) => index.filter_candidates(state),
+        None => state.filter_candidates(word_list),
+    }
+}
+
+/// Prints a short summary of the just-recorded round.
+fn report_round(history: &[SavedRound], max_guesses: usize) {
+    let round = history.last().expect("a round was just pushed");
+    println!(
+        "Guess {}/{}: {} ({})",
+        history.len(),
+        max_guesses,
+        round.guess,
+        if round.solver_chosen {
+            "solver"
+        } else {
+            "manual"
+        }
+    );
+}
@@ -0,0 +1,99 @@
+/*******************************************************************************
+* Wordle SolvRS - A wordle solver written in Rust
+*
+* The MIT License (MIT)
+* Copyright (c) 2025 Teeto44
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to
+* deal in the Software without restriction, including without limitation the
+* rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+* sell copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in all
+* copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*******************************************************************************/
+
+use crate::core::{Feedback, SolverState};
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// One played round: the guess, the feedback it received, and whether the guess itself was
+/// typed in by the user or proposed by the solver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedRound {
+    pub guess: String,
+    pub feedback: [Feedback; 5],
+    pub solver_chosen: bool,
+}
+
+/// A durable, JSON-serializable snapshot of a game: the full round history plus the
+/// green/yellow/gray/min_counts state it derives, so a session can be paused, shared, and
+/// replayed deterministically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame {
+    pub rounds: Vec<SavedRound>,
+    pub state: SolverState,
+}
+
+impl SavedGame {
+    /// Rebuilds a `SavedGame` by replaying a round history from scratch, so the derived state is
+    /// always consistent with the rounds that produced it.
+    pub fn from_rounds(rounds: Vec<SavedRound>) -> Self {
+        let mut state = SolverState::default();
+        for round in &rounds {
+            state.apply_feedback(&round.guess, &round.feedback);
+        }
+        SavedGame { rounds, state }
+    }
+}
+
+/// Saves a game to a JSON file.
+pub fn save_to_file(path: &str, game: &SavedGame) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(game).map_err(|err| err.to_string())?;
+    fs::write(path, json).map_err(|err| err.to_string())
+}
+
+/// Loads a game from a JSON file.
+pub fn load_from_file(path: &str) -> Result<SavedGame, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    serde_json::from_str(&contents).map_err(|err| err.to_string())
+}
+
+/// Parses the legacy `-s/--state` comma-separated `guess+feedback` format into a round history,
+/// kept for compatibility with sessions saved before JSON support existed. The legacy format
+/// doesn't track whether a guess was manual or solver-selected, so every round is marked
+/// solver-selected, including a first-word round that the solve loop itself would record as
+/// not solver-chosen.
+pub fn parse_legacy_state(data: &str) -> Vec<SavedRound> {
+    data.split(',')
+        .map(str::trim)
+        .filter_map(|entry| {
+            if entry.len() != 10 {
+                eprintln!("Warning: skipping invalid entry `{}`", entry);
+                return None;
+            }
+            let (guess, feedback_entry) = entry.split_at(5);
+            match Feedback::parse_str(feedback_entry) {
+                Some(feedback) => Some(SavedRound {
+                    guess: guess.to_string(),
+                    feedback,
+                    solver_chosen: true,
+                }),
+                None => {
+                    eprintln!("Warning: invalid feedback in `{}`", entry);
+                    None
+                }
+            }
+        })
+        .collect()
+}
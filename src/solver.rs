@@ -24,17 +24,33 @@
 *******************************************************************************/
 
 use crate::core::*;
+use crate::fst_index::FstIndex;
+use crate::save::{self, SavedGame, SavedRound};
+use crate::strategy::Solver;
 use prompted::input;
-use std::collections::{HashMap, HashSet};
+
+/// Outcome of a single solve attempt: whether it succeeded, how many guesses it took, and the
+/// full round history (guess, feedback, and who chose the guess). Used both to report
+/// interactive results and to feed the benchmark harness.
+#[derive(Debug, Clone)]
+pub struct SolveOutcome {
+    pub solved: bool,
+    pub guesses: usize,
+    pub rounds: Vec<SavedRound>,
+}
 
 /// Solves the wordle.
+#[allow(clippy::too_many_arguments)]
 pub fn solve(
     word_list: Vec<String>,
     test_answer: Option<String>,
-    initial_state: Option<String>,
+    initial_rounds: Option<Vec<SavedRound>>,
     chosen_first: Option<String>,
     max_guesses: usize,
-) {
+    solver: &dyn Solver,
+    fst_index: Option<&FstIndex>,
+    save_path: Option<&str>,
+) -> SolveOutcome {
     println!(
         "Wordle SolvRS - {}{}",
         if test_answer.is_some() {
@@ -46,77 +62,169 @@ pub fn solve(
             .as_deref()
             .map_or("".to_string(), |word| format!("{}'", word))
     );
+    println!("Solver: {}", solver.name());
 
-    let mut green: [Option<char>; 5] = [None; 5];
-    let mut yellow: Vec<(char, usize)> = Vec::new();
-    let mut gray: HashSet<char> = HashSet::new();
-    let mut min_counts: HashMap<char, usize> = HashMap::new();
+    let mut state = SolverState::default();
     let mut remaining_rounds = max_guesses;
     let first_word = chosen_first.unwrap_or_else(|| DEFAULT_FIRST_WORD.to_string());
 
-    // Load previous state in finish mode
-    if test_answer.is_none() {
-        if let Some(state) = initial_state {
-            load_state(
-                &state,
-                &mut green,
-                &mut yellow,
-                &mut gray,
-                &mut min_counts,
-                &mut remaining_rounds,
-            );
+    // Load previous rounds in finish mode
+    let previous_rounds = if test_answer.is_none() {
+        initial_rounds.unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    for round in &previous_rounds {
+        state.apply_feedback(&round.guess, &round.feedback);
+    }
+    remaining_rounds = remaining_rounds.saturating_sub(previous_rounds.len());
+
+    let start_round = max_guesses - remaining_rounds + 1;
+    let mut outcome = run_rounds(
+        &word_list,
+        &first_word,
+        start_round,
+        max_guesses,
+        state,
+        solver,
+        fst_index,
+        true,
+        |guess| match test_answer.as_deref() {
+            Some(answer) => generate_feedback(guess, answer),
+            None => manual_feedback(guess),
+        },
+    );
+
+    if outcome.solved {
+        println!("Solved in {} rounds.", outcome.guesses);
+    } else {
+        println!("Failed to solve the puzzle in {} guesses.", max_guesses);
+    }
+
+    // Report and persist the full session, including any rounds loaded from a previous save
+    let mut all_rounds = previous_rounds;
+    all_rounds.append(&mut outcome.rounds);
+    outcome.rounds = all_rounds;
+    if let Some(path) = save_path {
+        let game = SavedGame::from_rounds(outcome.rounds.clone());
+        if let Err(err) = save::save_to_file(path, &game) {
+            eprintln!("Error: couldn't save game to `{}`: {}", path, err);
         }
     }
 
-    // Main loop
-    for round in 1..=remaining_rounds {
-        // Find valid words
-        let candidates = filter_candidates(&word_list, &green, &yellow, &gray, &min_counts);
+    outcome
+}
+
+/// Runs the solver against a known answer with no interactive I/O, for use by the benchmark
+/// harness where thousands of independent runs need to execute quickly in parallel.
+pub fn solve_silent(
+    word_list: &[String],
+    answer: &str,
+    first_word: &str,
+    max_guesses: usize,
+    solver: &dyn Solver,
+    fst_index: Option<&FstIndex>,
+) -> SolveOutcome {
+    run_rounds(
+        word_list,
+        first_word,
+        1,
+        max_guesses,
+        SolverState::default(),
+        solver,
+        fst_index,
+        false,
+        |guess| generate_feedback(guess, answer),
+    )
+}
+
+/// Shared core loop: plays `first_word` on round 1 and picks every later guess via the chosen
+/// `Solver`, obtains feedback via
+/// `next_feedback`, and updates the accumulated state, returning a structured outcome. Per-round
+/// progress is only printed when `verbose` is set, so the silent benchmark path can run this at
+/// full speed.
+#[allow(clippy::too_many_arguments)]
+fn run_rounds<F>(
+    word_list: &[String],
+    first_word: &str,
+    start_round: usize,
+    max_guesses: usize,
+    mut state: SolverState,
+    solver: &dyn Solver,
+    fst_index: Option<&FstIndex>,
+    verbose: bool,
+    mut next_feedback: F,
+) -> SolveOutcome
+where
+    F: FnMut(&str) -> [Feedback; 5],
+{
+    let mut rounds = Vec::new();
+
+    for guess_count in start_round..=max_guesses {
+        // Find valid words, preferring the FST-backed index when one was built
+        let candidates = match fst_index {
+            Some(index) => index.filter_candidates(&state),
+            None => state.filter_candidates(word_list),
+        };
 
         // Select guess
-        let guess_count = round + max_guesses - remaining_rounds;
-        let (guess, total_candidates) = if guess_count == 1 {
-            (first_word.as_str(), word_list.len())
+        let solver_chosen = guess_count != 1;
+        let (guess, total_candidates) = if !solver_chosen {
+            (first_word.to_string(), word_list.len())
         } else {
-            select_guess(&candidates).unwrap_or_else(|| {
-                eprintln!("Error: no possible candidates, exiting.");
-                std::process::exit(1)
-            })
+            match solver.next_guess(&candidates, word_list, &state) {
+                Some((word, count)) => (word, count),
+                None => {
+                    if verbose {
+                        eprintln!("Error: no possible candidates, exiting.");
+                        std::process::exit(1);
+                    }
+                    break;
+                }
+            }
         };
 
-        println!(
-            "Guess {}: {} ({} candidates)",
-            guess_count, guess, total_candidates
-        );
+        if verbose {
+            println!(
+                "Guess {}: {} ({} candidates)",
+                guess_count, guess, total_candidates
+            );
+            if let Some(extra) = solver.describe_choice(&guess, &candidates, word_list) {
+                println!("  {}", extra);
+            }
+        }
 
         // Accept feedback
-        let feedback = match test_answer.as_deref() {
-            Some(word) => generate_feedback(guess, word),
-            None => manual_feedback(guess),
-        };
+        let feedback = next_feedback(&guess);
+        let solved = feedback.iter().all(|&f| f == Feedback::Green);
+        rounds.push(SavedRound {
+            guess: guess.clone(),
+            feedback,
+            solver_chosen,
+        });
 
         // Check for success
-        if feedback.iter().all(|&f| f == Feedback::Green) {
-            println!("Solved in {} rounds.", guess_count);
-            return;
+        if solved {
+            return SolveOutcome {
+                solved: true,
+                guesses: guess_count,
+                rounds,
+            };
         }
 
         // Record feedback
-        apply_feedback(
-            guess,
-            &feedback,
-            &mut green,
-            &mut yellow,
-            &mut gray,
-            &mut min_counts,
-        );
+        state.apply_feedback(&guess, &feedback);
     }
 
-    println!("Failed to solve the puzzle in {} guesses.", max_guesses);
+    SolveOutcome {
+        solved: false,
+        guesses: rounds.len(),
+        rounds,
+    }
 }
 
 /// Prompt the user and parse a 5‑char feedback string.
-fn manual_feedback(guess: &str) -> [Feedback; 5] {
+pub(crate) fn manual_feedback(guess: &str) -> [Feedback; 5] {
     loop {
         // Prompt for feedback
         let feedback = input!(
@@ -132,27 +240,18 @@ fn manual_feedback(guess: &str) -> [Feedback; 5] {
             std::process::exit(0);
         }
 
-        // Check length
-        if feedback.len() != 5 {
-            eprintln!("Error: feedback must be 5 characters.");
-            continue;
-        }
-
-        // Check for valid characters
-        let mut feedback_array = [Feedback::Gray; 5];
-        for (index, char) in feedback.chars().enumerate() {
-            feedback_array[index] = Feedback::from_char(char).unwrap_or_else(|| {
-                eprintln!("Error: invalid feedback `{}`", char);
-                std::process::exit(1)
-            });
+        match Feedback::parse_str(&feedback) {
+            Some(feedback_array) => return feedback_array,
+            None => {
+                eprintln!("Error: feedback must be 5 characters of g/y/b.");
+                continue;
+            }
         }
-
-        return feedback_array;
     }
 }
 
 /// Automated feedback generator.
-fn generate_feedback(guess: &str, answer: &str) -> [Feedback; 5] {
+pub(crate) fn generate_feedback(guess: &str, answer: &str) -> [Feedback; 5] {
     let mut result = [Feedback::Gray; 5];
     let mut remaining_chars: Vec<Option<char>> = answer.chars().map(Some).collect();
 
@@ -175,42 +274,3 @@ fn generate_feedback(guess: &str, answer: &str) -> [Feedback; 5] {
     }
     result
 }
-
-/// Loads a comma‑separated history of guess+feedback pairs.
-fn load_state(
-    data: &str,
-    green: &mut [Option<char>; 5],
-    yellow: &mut Vec<(char, usize)>,
-    gray: &mut HashSet<char>,
-    min_counts: &mut HashMap<char, usize>,
-    remaining: &mut usize,
-) {
-    let mut applied_guesses = 0;
-
-    for entry in data.split(',').map(str::trim) {
-        if entry.len() != 10 {
-            eprintln!("Warning: skipping invalid entry `{}`", entry);
-            continue;
-        }
-        let (guess, feedback_entry) = entry.split_at(5);
-        let mut feedback = [Feedback::Gray; 5];
-        let mut valid = true;
-
-        for (index, char) in feedback_entry.chars().enumerate() {
-            match Feedback::from_char(char) {
-                Some(feedback_char) => feedback[index] = feedback_char,
-                None => {
-                    eprintln!("Warning: invalid feedback `{}` in `{}`", char, entry);
-                    valid = false;
-                    break;
-                }
-            }
-        }
-        if !valid {
-            continue;
-        }
-        apply_feedback(guess, &feedback, green, yellow, gray, min_counts);
-        applied_guesses += 1;
-    }
-    *remaining = remaining.saturating_sub(applied_guesses);
-}
@@ -0,0 +1,239 @@
+/*******************************************************************************
+* Wordle SolvRS - A wordle solver written in Rust
+*
+* The MIT License (MIT)
+* Copyright (c) 2025 Teeto44
+*
+* Permission is hereby granted, free of charge, to any person obtaining a copy
+* of this software and associated documentation files (the "Software"), to
+* deal in the Software without restriction, including without limitation the
+* rights to use, copy, modify, merge, publish, distribute, sublicense, and/or
+* sell copies of the Software, and to permit persons to whom the Software is
+* furnished to do so, subject to the following conditions:
+*
+* The above copyright notice and this permission notice shall be included in all
+* copies or substantial portions of the Software.
+*
+* THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+* IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+* FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+* AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+* LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+* OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+* THE SOFTWARE.
+*******************************************************************************/
+
+use crate::core::{select_guess, Feedback, SolverState};
+use crate::solver::generate_feedback;
+use std::collections::{HashMap, HashSet};
+
+/// A pluggable guess‑selection strategy, so the solver loop and the benchmark harness can compare
+/// different heuristics on equal footing. `Send + Sync` so a single strategy can be shared across
+/// the benchmark's parallel runs.
+pub trait Solver: Send + Sync {
+    /// Chooses the next guess given the surviving candidates, the full word list, and the
+    /// constraints accumulated so far. Returns the guess and the candidate count it was chosen
+    /// from, or `None` if there are no possible candidates.
+    fn next_guess(
+        &self,
+        candidates: &[String],
+        full_list: &[String],
+        state: &SolverState,
+    ) -> Option<(String, usize)>;
+
+    /// The name used to select this strategy with `--solver`.
+    fn name(&self) -> &'static str;
+
+    /// An optional extra line to print alongside the chosen guess, e.g. an expected-information
+    /// score. Strategies with nothing to add can leave the default.
+    fn describe_choice(
+        &self,
+        _guess: &str,
+        _candidates: &[String],
+        _full_list: &[String],
+    ) -> Option<String> {
+        None
+    }
+}
+
+/// The original heuristic: rewards unique letters and vowels.
+pub struct Naive;
+
+impl Solver for Naive {
+    fn next_guess(
+        &self,
+        candidates: &[String],
+        _full_list: &[String],
+        _state: &SolverState,
+    ) -> Option<(String, usize)> {
+        select_guess(candidates).map(|(word, count)| (word.to_string(), count))
+    }
+
+    fn name(&self) -> &'static str {
+        "naive"
+    }
+}
+
+/// Scores each candidate by the summed positional letter frequencies across the full word list,
+/// favouring guesses built from letters that are common in the position they'd appear. The
+/// per-position frequency table only depends on the word list, so it's built once up front
+/// instead of being recomputed on every guess of every benchmark run.
+pub struct Frequency {
+    position_counts: [HashMap<char, usize>; 5],
+}
+
+impl Frequency {
+    /// Builds the positional letter-frequency table from the full word list.
+    pub fn new(full_list: &[String]) -> Self {
+        let mut position_counts: [HashMap<char, usize>; 5] = Default::default();
+        for word in full_list {
+            for (index, char) in word.chars().enumerate() {
+                *position_counts[index].entry(char).or_default() += 1;
+            }
+        }
+        Frequency { position_counts }
+    }
+}
+
+impl Solver for Frequency {
+    fn next_guess(
+        &self,
+        candidates: &[String],
+        _full_list: &[String],
+        _state: &SolverState,
+    ) -> Option<(String, usize)> {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let score = |word: &String| -> usize {
+            word.chars()
+                .enumerate()
+                .map(|(index, char)| self.position_counts[index].get(&char).copied().unwrap_or(0))
+                .sum()
+        };
+
+        let best = candidates
+            .iter()
+            .max_by_key(|word| score(word))
+            .expect("candidates is non-empty");
+
+        Some((best.clone(), candidates.len()))
+    }
+
+    fn name(&self) -> &'static str {
+        "frequency"
+    }
+}
+
+/// Picks the guess that maximizes expected information gain. For every potential guess (drawn
+/// from the full word list, since a non-candidate can still split the candidate set better),
+/// partitions the remaining candidates by the feedback pattern the guess would produce against
+/// each one, then scores the guess by the Shannon entropy of that partition. Ties favour a guess
+/// that is itself still a candidate, so late-game guesses can actually win the round.
+pub struct Entropy;
+
+impl Entropy {
+    /// Encodes a 5-tile feedback pattern as a base-3 integer (green/yellow/gray -> 0/1/2), giving
+    /// up to 3^5 = 243 distinct buckets.
+    fn pattern_index(guess: &str, answer: &str) -> usize {
+        generate_feedback(guess, answer)
+            .iter()
+            .fold(0usize, |acc, feedback| {
+                let digit = match feedback {
+                    Feedback::Green => 0,
+                    Feedback::Yellow => 1,
+                    Feedback::Gray => 2,
+                };
+                acc * 3 + digit
+            })
+    }
+
+    /// Expected information in bits: `H(g) = -sum(p_i * log2(p_i))` over the buckets that
+    /// `guess` would split `candidates` into.
+    fn expected_bits(guess: &str, candidates: &[String]) -> f64 {
+        let mut buckets = [0usize; 243];
+        for answer in candidates {
+            buckets[Self::pattern_index(guess, answer)] += 1;
+        }
+
+        let total = candidates.len() as f64;
+        buckets
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+impl Solver for Entropy {
+    fn next_guess(
+        &self,
+        candidates: &[String],
+        full_list: &[String],
+        _state: &SolverState,
+    ) -> Option<(String, usize)> {
+        if candidates.is_empty() {
+            return None;
+        }
+        if candidates.len() == 1 {
+            return Some((candidates[0].clone(), 1));
+        }
+
+        let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+        let mut best: Option<(&String, f64)> = None;
+
+        for guess in full_list {
+            let bits = Self::expected_bits(guess, candidates);
+            let beats_current = match best {
+                None => true,
+                Some((best_word, best_bits)) => {
+                    bits > best_bits
+                        || (bits == best_bits
+                            && candidate_set.contains(guess.as_str())
+                            && !candidate_set.contains(best_word.as_str()))
+                }
+            };
+            if beats_current {
+                best = Some((guess, bits));
+            }
+        }
+
+        best.map(|(word, _)| (word.clone(), candidates.len()))
+    }
+
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn describe_choice(
+        &self,
+        guess: &str,
+        candidates: &[String],
+        _full_list: &[String],
+    ) -> Option<String> {
+        if candidates.len() <= 1 {
+            return None;
+        }
+        let bits = Self::expected_bits(guess, candidates);
+        Some(format!("expected information: {:.3} bits", bits))
+    }
+}
+
+/// Resolves a `--solver` flag value to a boxed strategy, falling back to `Naive` for unknown
+/// or missing names. Takes the full word list since `Frequency` needs it up front to build its
+/// positional frequency table.
+pub fn resolve(name: Option<&str>, full_list: &[String]) -> Box<dyn Solver> {
+    match name {
+        Some("frequency") => Box::new(Frequency::new(full_list)),
+        Some("entropy") => Box::new(Entropy),
+        None | Some("naive") => Box::new(Naive),
+        Some(other) => {
+            eprintln!("Unknown solver `{}`; using default `naive`.", other);
+            Box::new(Naive)
+        }
+    }
+}